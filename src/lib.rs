@@ -55,7 +55,7 @@
 //! }
 //!
 //! fn main() {
-//!     let mut queue = MinBinaryHeap::new();
+//!     let mut queue: MinBinaryHeap<Job> = MinBinaryHeap::new();
 //!
 //!     queue.insert(Job { id: 1, time_left: 5 });
 //!     queue.insert(Job { id: 2, time_left: 6 });
@@ -66,17 +66,149 @@
 //!     assert_eq!(queue.extract_min(), Some(Job { id: 2, time_left: 6 }));
 //! }
 //! ```
+//!
+//! # Indexed mode
+//!
+//! Algorithms such as Dijkstra's shortest path need to lower the key of an element that is
+//! already sitting in the queue, rather than inserting a duplicate. To support this, elements may
+//! implement `Indexing` so the heap can track where each of them currently lives and expose
+//! `decrease_key`/`increase_key` in O(log(n)). Plain `Ord` elements never need to implement it:
+//! only heaps built with `with_max_index` (and the key-update methods that go with it) require it.
+//!
+//! ```rust
+//! extern crate min_binary_heap;
+//!
+//! use std::cmp::Ordering;
+//! use min_binary_heap::{Indexing, MinBinaryHeap};
+//!
+//! #[derive(Debug, Eq, PartialEq)]
+//! struct Distance {
+//!     node: usize,
+//!     cost: u32,
+//! }
+//!
+//! impl Ord for Distance {
+//!     fn cmp(&self, other: &Distance) -> Ordering {
+//!         self.cost.cmp(&other.cost)
+//!     }
+//! }
+//!
+//! impl PartialOrd for Distance {
+//!     fn partial_cmp(&self, other: &Distance) -> Option<Ordering> {
+//!         Some(self.cmp(other))
+//!     }
+//! }
+//!
+//! impl Indexing for Distance {
+//!     fn as_index(&self) -> usize {
+//!         self.node
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let mut queue: MinBinaryHeap<Distance> = MinBinaryHeap::with_max_index(3);
+//!
+//!     queue.insert(Distance { node: 0, cost: 10 });
+//!     queue.insert(Distance { node: 1, cost: 4 });
+//!
+//!     queue.decrease_key(Distance { node: 0, cost: 1 });
+//!
+//!     assert_eq!(queue.extract_min(), Some(Distance { node: 0, cost: 1 }));
+//! }
+//! ```
+//!
+//! # Pluggable backing storage
+//!
+//! The heap does not hardcode `Vec<T>` as its backing array: it is generic over any type that
+//! implements `HeapStore<T>`, with `Vec<T>` as the default. This lets the same sift logic run over
+//! storage other than plain RAM, such as the persisted [`store::PersistentStore`] described there.
+
+use std::ops::{Deref, DerefMut};
+
+pub mod store;
+
+pub use store::{HeapStore, OpenError, Persist, PersistentStore};
+
+/// Lets an element report the stable, dense index it occupies outside of the heap (for example a
+/// graph node id), so that `MinBinaryHeap` can track its current slot and support `decrease_key`.
+pub trait Indexing {
+    /// Returns the logical index this element is identified by.
+    fn as_index(&self) -> usize;
+}
+
+/// Sentinel stored in `positions` for a logical index that is not currently in the heap.
+const NOT_PRESENT: usize = usize::MAX;
+
+macro_rules! impl_indexing_for_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Indexing for $t {
+                fn as_index(&self) -> usize {
+                    *self as usize
+                }
+            }
+        )*
+    };
+}
 
-/// A min-priority queue implemented with a binary heap.
+impl_indexing_for_unsigned!(u8, u16, u32, u64, usize);
+
+/// The number of children each node has in a heap created without an explicit arity.
+const DEFAULT_ARITY: usize = 2;
+
+/// A min-priority queue implemented with a d-ary heap.
+///
+/// The elements of the queue only need to implement the standard library's `Ord` trait i.e. the
+/// type forms a total order. Elements additionally need `Indexing` solely to use the heap's
+/// indexed mode (`with_max_index`, `decrease_key`, `increase_key`, `update_key`, `contains`); the
+/// rest of the API works for any `T: Ord`.
 ///
-/// The elements of the queue are of a type which implements the standard library's `Ord` trait
-/// i.e. the type forms a total order.
-pub struct MinBinaryHeap<T> {
-    tree: Vec<T>,
+/// By default each node has `2` children, giving the classic binary heap. Wider heaps (built with
+/// `with_arity`) do fewer, shallower swaps on `extract_min` at the cost of scanning more children
+/// per node, which tends to pay off for workloads such as Dijkstra's algorithm that call
+/// `extract_min`/`decrease_key` far more often than they grow the heap.
+///
+/// `S` is the backing array and defaults to `Vec<T>`; any other `HeapStore<T>` implementation
+/// (see `with_store`) can be used in its place.
+pub struct MinBinaryHeap<T, S = Vec<T>>
+    where S: HeapStore<T>
+{
+    tree: S,
+    positions: Vec<usize>,
+    arity: usize,
+    /// Set only by `with_max_index`, where `T: Indexing` is known; lets the rest of the heap's
+    /// methods keep `positions` in sync without themselves requiring `T: Indexing`.
+    index_of: Option<fn(&T) -> usize>,
 }
 
-impl<T> MinBinaryHeap<T>
-    where T: Ord
+/// Guard returned by `MinBinaryHeap::peek_mut`. Grants mutable access to the smallest item and
+/// re-sifts it down into place when dropped.
+pub struct PeekMut<'a, T: 'a + Ord, S: 'a + HeapStore<T>> {
+    heap: &'a mut MinBinaryHeap<T, S>,
+}
+
+impl<'a, T: 'a + Ord, S: 'a + HeapStore<T>> Drop for PeekMut<'a, T, S> {
+    fn drop(&mut self) {
+        self.heap.trickle_down(0);
+    }
+}
+
+impl<'a, T: 'a + Ord, S: 'a + HeapStore<T>> Deref for PeekMut<'a, T, S> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.heap.tree.get(0)
+    }
+}
+
+impl<'a, T: 'a + Ord, S: 'a + HeapStore<T>> DerefMut for PeekMut<'a, T, S> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.heap.tree.get_mut(0)
+    }
+}
+
+impl<T, S> MinBinaryHeap<T, S>
+    where T: Ord, S: HeapStore<T>
 {
     /// Removes the smallest item from the binary heap and returns it, or `None` if heap is empty.
     ///
@@ -86,7 +218,7 @@ impl<T> MinBinaryHeap<T>
     ///
     /// ```
     /// use min_binary_heap::MinBinaryHeap;
-    /// let mut queue = MinBinaryHeap::new();
+    /// let mut queue: MinBinaryHeap<u8> = MinBinaryHeap::new();
     /// queue.insert(10);
     /// queue.insert(1);
     ///
@@ -100,12 +232,15 @@ impl<T> MinBinaryHeap<T>
             return None;
         } else if self.tree.len() < 2 {
             // There is only one element.
-            return Some(self.tree.pop().unwrap());
+            let minimum = self.tree.pop().unwrap();
+            self.mark_absent_for(&minimum);
+            return Some(minimum);
         }
         // Remove root and replace it with last node given by BFS.
         let last_node_index = self.tree.len() - 1;
-        self.tree.swap(0, last_node_index);
+        self.swap_tracked(0, last_node_index);
         let minimum = self.tree.pop().unwrap();
+        self.mark_absent_for(&minimum);
 
         if self.tree.len() > 1 {
             let mut current_index = 0;
@@ -114,8 +249,8 @@ impl<T> MinBinaryHeap<T>
                 None => return Some(minimum),
             };
             // Trickle root down tree.
-            while self.tree[current_index] > self.tree[child_index_min] {
-                self.tree.swap(current_index, child_index_min);
+            while self.tree.get(current_index) > self.tree.get(child_index_min) {
+                self.swap_tracked(current_index, child_index_min);
 
                 current_index = child_index_min;
                 child_index_min = match self.child_index_min(current_index) {
@@ -137,37 +272,25 @@ impl<T> MinBinaryHeap<T>
     fn parent_index(&self, of_index: usize) -> usize {
         if of_index < 1 || of_index >= self.size() {
             panic!("MinBinaryHeap index out of bounds.");
-        } else if of_index % 2 == 0 {
-            (of_index - 2) / 2
-        } else {
-            (of_index - 1) / 2
         }
+        (of_index - 1) / self.arity
     }
 
     /// Returns the index of the smallest child node of a given parent node with index `i`
     /// or `None` if no children exist.
     fn child_index_min(&self, of_index: usize) -> Option<usize> {
-        // Compute potential indexes of children.
-        let left_child_index = 2 * of_index + 1;
-        let right_child_index = 2 * of_index + 2;
-
-        if left_child_index < self.tree.len() {
-            // Left child exists...
-            if right_child_index < self.tree.len() {
-                // Right child exists... Return index of minimum child node.
-                if self.tree[left_child_index] < self.tree[right_child_index] {
-                    Some(left_child_index)
-                } else {
-                    Some(right_child_index)
-                }
-            } else {
-                // Only left child exists.
-                Some(left_child_index)
-            }
-        } else {
-            // No children.
-            None
-        }
+        self.child_index_min_within(of_index, self.tree.len())
+    }
+
+    /// Returns the index of the smallest child node of a given parent node with index `i` within
+    /// the first `len` slots of `tree`, or `None` if no children exist there.
+    fn child_index_min_within(&self, of_index: usize, len: usize) -> Option<usize> {
+        let first_child_index = self.arity * of_index + 1;
+        let last_child_index = self.arity * of_index + self.arity;
+
+        (first_child_index..=last_child_index)
+            .take_while(|&child_index| child_index < len)
+            .min_by(|&a, &b| self.tree.get(a).cmp(self.tree.get(b)))
     }
 
     /// Returns the number of elements present inside the binary heap (queue).
@@ -178,7 +301,7 @@ impl<T> MinBinaryHeap<T>
     ///
     /// ```
     /// use min_binary_heap::MinBinaryHeap;
-    /// let mut queue = MinBinaryHeap::new();
+    /// let mut queue: MinBinaryHeap<u8> = MinBinaryHeap::new();
     /// queue.insert(10);
     /// queue.insert(1);
     ///
@@ -189,6 +312,58 @@ impl<T> MinBinaryHeap<T>
         self.tree.len()
     }
 
+    /// Returns a reference to the smallest item in the binary heap, or `None` if heap is empty.
+    ///
+    /// Unlike `extract_min` this does not remove the item, so it runs in O(1).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use min_binary_heap::MinBinaryHeap;
+    /// let mut queue: MinBinaryHeap<u8> = MinBinaryHeap::new();
+    /// queue.insert(10);
+    /// queue.insert(1);
+    ///
+    /// assert_eq!(queue.peek(), Some(&1));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.tree.first()
+    }
+
+    /// Returns a guard granting mutable access to the smallest item in the binary heap, or `None`
+    /// if heap is empty.
+    ///
+    /// The heap property is restored by re-sifting the item down when the guard is dropped, so
+    /// callers can adjust the smallest element in place without a pop/push round-trip.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use min_binary_heap::MinBinaryHeap;
+    /// let mut queue: MinBinaryHeap<u8> = MinBinaryHeap::new();
+    /// queue.insert(10);
+    /// queue.insert(1);
+    ///
+    /// {
+    ///     let mut smallest = queue.peek_mut().unwrap();
+    ///     *smallest = 20;
+    /// }
+    ///
+    /// assert_eq!(queue.extract_min(), Some(10));
+    /// assert_eq!(queue.extract_min(), Some(20));
+    /// ```
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T, S>> {
+        if self.tree.is_empty() {
+            None
+        } else {
+            Some(PeekMut { heap: self })
+        }
+    }
+
     /// Insert elements into the binary heap.
     ///
     /// Examples
@@ -197,7 +372,7 @@ impl<T> MinBinaryHeap<T>
     ///
     /// ```
     /// use min_binary_heap::MinBinaryHeap;
-    /// let mut queue = MinBinaryHeap::new();
+    /// let mut queue: MinBinaryHeap<u8> = MinBinaryHeap::new();
     /// queue.insert(10);
     /// queue.insert(1);
     /// queue.insert(5);
@@ -206,25 +381,188 @@ impl<T> MinBinaryHeap<T>
     /// assert_eq!(queue.extract_min(), Some(1));
     /// ```
     pub fn insert(&mut self, new_node: T) {
+        let new_index = self.index_of.map(|as_index| as_index(&new_node));
         self.tree.push(new_node);
+        if let Some(new_index) = new_index {
+            self.track(new_index, self.tree.len() - 1);
+        }
 
-        if self.tree.len() > 1 {
-            let mut current_index = self.tree.len() - 1;
-            let mut parent_index = self.parent_index(current_index);
-            // Bubble new node up tree.
-            while self.tree[current_index] < self.tree[parent_index] {
-                self.tree.swap(current_index, parent_index);
-
-                if parent_index < 1 {
-                    break;
-                }
+        self.bubble_up(self.tree.len() - 1);
+    }
+
+    /// Returns `true` if an element with logical index `idx` is currently present in the heap.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use min_binary_heap::{Indexing, MinBinaryHeap};
+    ///
+    /// struct Entry(usize);
+    /// impl Indexing for Entry {
+    ///     fn as_index(&self) -> usize { self.0 }
+    /// }
+    /// impl Eq for Entry {}
+    /// impl PartialEq for Entry {
+    ///     fn eq(&self, other: &Entry) -> bool { self.0 == other.0 }
+    /// }
+    /// impl Ord for Entry {
+    ///     fn cmp(&self, other: &Entry) -> std::cmp::Ordering { self.0.cmp(&other.0) }
+    /// }
+    /// impl PartialOrd for Entry {
+    ///     fn partial_cmp(&self, other: &Entry) -> Option<std::cmp::Ordering> { Some(self.cmp(other)) }
+    /// }
+    ///
+    /// let mut queue: MinBinaryHeap<Entry> = MinBinaryHeap::with_max_index(1);
+    /// assert!(!queue.contains(0));
+    /// queue.insert(Entry(0));
+    /// assert!(queue.contains(0));
+    /// ```
+    pub fn contains(&self, idx: usize) -> bool {
+        idx < self.positions.len() && self.positions[idx] != NOT_PRESENT
+    }
 
-                current_index = parent_index;
-                parent_index = self.parent_index(current_index);
+    /// Bubbles the node at `start_index` up towards the root while it is smaller than its parent.
+    fn bubble_up(&mut self, start_index: usize) {
+        if start_index < 1 {
+            return;
+        }
+
+        let mut current_index = start_index;
+        let mut parent_index = self.parent_index(current_index);
+        while self.tree.get(current_index) < self.tree.get(parent_index) {
+            self.swap_tracked(current_index, parent_index);
+
+            if parent_index < 1 {
+                break;
+            }
+
+            current_index = parent_index;
+            parent_index = self.parent_index(current_index);
+        }
+    }
+
+    /// Trickles the node at `start_index` down towards the leaves while it is larger than its
+    /// smallest child.
+    fn trickle_down(&mut self, start_index: usize) {
+        let len = self.tree.len();
+        self.trickle_down_within(start_index, len);
+    }
+
+    /// Trickles the node at `start_index` down towards the leaves, treating only the first `len`
+    /// slots of `tree` as part of the heap. Used by `into_sorted_vec`, which shrinks the logical
+    /// length of the heap as it pulls sorted elements off the end of `tree`.
+    fn trickle_down_within(&mut self, start_index: usize, len: usize) {
+        let mut current_index = start_index;
+        let mut child_index_min = match self.child_index_min_within(current_index, len) {
+            Some(index) => index,
+            None => return,
+        };
+        while self.tree.get(current_index) > self.tree.get(child_index_min) {
+            self.swap_tracked(current_index, child_index_min);
+
+            current_index = child_index_min;
+            child_index_min = match self.child_index_min_within(current_index, len) {
+                Some(index) => index,
+                None => break,
             }
         }
     }
 
+    /// Swaps the two slots in `tree` and keeps `positions` in sync with their new locations, when
+    /// `T` is being tracked at all (i.e. the heap was created with `with_max_index`).
+    fn swap_tracked(&mut self, i: usize, j: usize) {
+        self.tree.swap(i, j);
+        if let Some(as_index) = self.index_of {
+            let index_i = as_index(self.tree.get(i));
+            self.track(index_i, i);
+            let index_j = as_index(self.tree.get(j));
+            self.track(index_j, j);
+        }
+    }
+
+    /// Records that the element with logical index `idx` now lives at slot `pos`.
+    fn track(&mut self, idx: usize, pos: usize) {
+        if idx < self.positions.len() {
+            self.positions[idx] = pos;
+        }
+    }
+
+    /// Records that `element` is no longer present in the heap, when `T` is being tracked at all.
+    fn mark_absent_for(&mut self, element: &T) {
+        if let Some(as_index) = self.index_of {
+            self.track(as_index(element), NOT_PRESENT);
+        }
+    }
+
+    /// Returns the slot currently holding the element with logical index `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not currently present in the heap.
+    fn slot_of(&self, idx: usize) -> usize {
+        if !self.contains(idx) {
+            panic!("MinBinaryHeap: logical index not present in heap.");
+        }
+        self.positions[idx]
+    }
+
+    /// Restores the heap property over the whole of `self.tree` in O(n) by trickling every
+    /// non-leaf node down, starting from the last one and working back towards the root.
+    fn heapify(&mut self) {
+        if self.tree.len() < 2 {
+            return;
+        }
+        let mut of_index = self.tree.len() / 2;
+        while of_index > 0 {
+            of_index -= 1;
+            self.trickle_down(of_index);
+        }
+    }
+
+    /// Marks every element currently in `tree` as present at its current slot. Used after an
+    /// operation replaces `tree` wholesale (bypassing `insert`/`swap_tracked`), so that tracked
+    /// indices stay in sync even for elements no swap ever touches.
+    fn retrack_all(&mut self) {
+        let Some(as_index) = self.index_of else {
+            return;
+        };
+        for slot in 0..self.tree.len() {
+            let idx = as_index(self.tree.get(slot));
+            self.track(idx, slot);
+        }
+    }
+
+    /// Creates an empty heap backed by an already-constructed store, e.g. a
+    /// [`store::PersistentStore`] reopened from a byte buffer.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use min_binary_heap::{MinBinaryHeap, PersistentStore};
+    /// let mut queue: MinBinaryHeap<u64, PersistentStore<u64>> =
+    ///     MinBinaryHeap::with_store(PersistentStore::new());
+    /// queue.insert(10);
+    /// queue.insert(1);
+    ///
+    /// assert_eq!(queue.extract_min(), Some(1));
+    /// ```
+    pub fn with_store(store: S) -> MinBinaryHeap<T, S> {
+        MinBinaryHeap {
+            tree: store,
+            positions: Vec::new(),
+            arity: DEFAULT_ARITY,
+            index_of: None,
+        }
+    }
+}
+
+impl<T, S> MinBinaryHeap<T, S>
+    where T: Ord, S: HeapStore<T> + Default
+{
     /// Helper function used to create an empty binary min-heap.
     ///
     /// # Examples
@@ -237,14 +575,280 @@ impl<T> MinBinaryHeap<T>
     ///
     /// assert_eq!(queue.size(), 0);
     /// ```
-    pub fn new() -> MinBinaryHeap<T> {
-        MinBinaryHeap { tree: Vec::new() }
+    pub fn new() -> MinBinaryHeap<T, S> {
+        MinBinaryHeap {
+            tree: S::default(),
+            positions: Vec::new(),
+            arity: DEFAULT_ARITY,
+            index_of: None,
+        }
+    }
+
+    /// Creates an empty heap where each node has `arity` children instead of the default `2`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `arity` is `0`, since a node without children can never be trickled into.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use min_binary_heap::MinBinaryHeap;
+    /// let mut queue: MinBinaryHeap<u8> = MinBinaryHeap::with_arity(4);
+    /// queue.insert(10);
+    /// queue.insert(1);
+    ///
+    /// assert_eq!(queue.extract_min(), Some(1));
+    /// ```
+    pub fn with_arity(arity: usize) -> MinBinaryHeap<T, S> {
+        if arity < 1 {
+            panic!("MinBinaryHeap arity must be at least 1.");
+        }
+        MinBinaryHeap {
+            tree: S::default(),
+            positions: Vec::new(),
+            arity,
+            index_of: None,
+        }
+    }
+}
+
+impl<T, S> MinBinaryHeap<T, S>
+    where T: Ord + Indexing, S: HeapStore<T> + Default
+{
+    /// Creates an empty binary min-heap whose `positions` table is pre-sized to hold logical
+    /// indices `0..max_index`, enabling `decrease_key`, `increase_key`, `update_key` and
+    /// `contains`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use min_binary_heap::MinBinaryHeap;
+    /// let queue: MinBinaryHeap<u8> = MinBinaryHeap::with_max_index(10);
+    ///
+    /// assert_eq!(queue.size(), 0);
+    /// ```
+    pub fn with_max_index(max_index: usize) -> MinBinaryHeap<T, S> {
+        Self::with_arity_and_max_index(DEFAULT_ARITY, max_index)
+    }
+
+    /// Creates an empty binary min-heap combining `with_arity` and `with_max_index`: each node
+    /// has `arity` children, and the `positions` table is pre-sized to hold logical indices
+    /// `0..max_index`, enabling `decrease_key`, `increase_key`, `update_key` and `contains`.
+    ///
+    /// This is the constructor Dijkstra-style callers reaching for a wider arity want, since
+    /// `with_arity` alone leaves `positions` empty and `with_max_index` alone is always 2-ary.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `arity` is `0`, since a node without children can never be trickled into.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use min_binary_heap::MinBinaryHeap;
+    /// let queue: MinBinaryHeap<u8> = MinBinaryHeap::with_arity_and_max_index(4, 10);
+    ///
+    /// assert_eq!(queue.size(), 0);
+    /// ```
+    pub fn with_arity_and_max_index(arity: usize, max_index: usize) -> MinBinaryHeap<T, S> {
+        if arity < 1 {
+            panic!("MinBinaryHeap arity must be at least 1.");
+        }
+        MinBinaryHeap {
+            tree: S::default(),
+            positions: vec![NOT_PRESENT; max_index],
+            arity,
+            index_of: Some(<T as Indexing>::as_index),
+        }
+    }
+}
+
+impl<T, S> MinBinaryHeap<T, S>
+    where T: Ord + Indexing, S: HeapStore<T>
+{
+    /// Lowers the value stored at `new`'s logical index (as given by `Indexing::as_index`) to
+    /// `new`, then bubbles it up towards the root until the heap property holds again.
+    ///
+    /// This is the operation Dijkstra-style algorithms need when a shorter path to an element
+    /// already queued is found: it avoids inserting a duplicate and runs in O(log(n)).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new`'s logical index is not currently present in the heap.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use min_binary_heap::{Indexing, MinBinaryHeap};
+    ///
+    /// struct Entry(usize, u8);
+    ///
+    /// impl Indexing for Entry {
+    ///     fn as_index(&self) -> usize { self.0 }
+    /// }
+    /// impl Eq for Entry {}
+    /// impl PartialEq for Entry {
+    ///     fn eq(&self, other: &Entry) -> bool { self.1 == other.1 }
+    /// }
+    /// impl Ord for Entry {
+    ///     fn cmp(&self, other: &Entry) -> std::cmp::Ordering { self.1.cmp(&other.1) }
+    /// }
+    /// impl PartialOrd for Entry {
+    ///     fn partial_cmp(&self, other: &Entry) -> Option<std::cmp::Ordering> { Some(self.cmp(other)) }
+    /// }
+    ///
+    /// let mut queue: MinBinaryHeap<Entry> = MinBinaryHeap::with_max_index(2);
+    /// queue.insert(Entry(0, 10));
+    /// queue.insert(Entry(1, 4));
+    ///
+    /// queue.decrease_key(Entry(0, 1));
+    ///
+    /// assert_eq!(queue.extract_min().unwrap().0, 0);
+    /// ```
+    pub fn decrease_key(&mut self, new: T) {
+        let slot = self.slot_of(new.as_index());
+        self.tree.set(slot, new);
+        self.bubble_up(slot);
+    }
+
+    /// Raises the value stored at `new`'s logical index to `new`, then trickles it down towards
+    /// the leaves until the heap property holds again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new`'s logical index is not currently present in the heap.
+    pub fn increase_key(&mut self, new: T) {
+        self.update_key(new);
+    }
+
+    /// Overwrites the value stored at `new`'s logical index with `new` and restores the heap
+    /// property, bubbling it up or trickling it down as required. Useful when the direction of
+    /// the change relative to the old value is not known ahead of time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new`'s logical index is not currently present in the heap.
+    pub fn update_key(&mut self, new: T) {
+        let slot = self.slot_of(new.as_index());
+        self.tree.set(slot, new);
+
+        if slot > 0 && self.tree.get(slot) < self.tree.get(self.parent_index(slot)) {
+            self.bubble_up(slot);
+        } else {
+            self.trickle_down(slot);
+        }
+    }
+}
+
+impl<T, S> MinBinaryHeap<T, S>
+    where T: Ord, S: HeapStore<T> + Into<Vec<T>>
+{
+    /// Consumes the binary heap and returns a `Vec<T>` sorted in ascending order.
+    ///
+    /// Since this works by repeatedly swapping the root with the last live element, shrinking the
+    /// logical length and sifting the new root down, it sorts `tree` in place; the crate gets an
+    /// O(n log(n)) heapsort for free.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use min_binary_heap::MinBinaryHeap;
+    /// let queue = MinBinaryHeap::from_vec(vec![10u8, 1, 5]);
+    ///
+    /// assert_eq!(queue.into_sorted_vec(), vec![1, 5, 10]);
+    /// ```
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut end = self.tree.len();
+        while end > 1 {
+            end -= 1;
+            self.swap_tracked(0, end);
+            self.trickle_down_within(0, end);
+        }
+        // `tree` is now sorted in descending order; flip it for a min-heap's ascending order.
+        self.tree.reverse();
+        self.tree.into()
+    }
+}
+
+impl<T> MinBinaryHeap<T, Vec<T>>
+    where T: Ord
+{
+    /// Builds a heap out of a `Vec<T>` in O(n) by heapifying it in place, rather than the O(n
+    /// log(n)) it would cost to `insert` each element one at a time.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use min_binary_heap::MinBinaryHeap;
+    /// let mut queue = MinBinaryHeap::from_vec(vec![10u8, 1, 5]);
+    ///
+    /// assert_eq!(queue.size(), 3);
+    /// assert_eq!(queue.extract_min(), Some(1));
+    /// ```
+    pub fn from_vec(v: Vec<T>) -> MinBinaryHeap<T, Vec<T>> {
+        let mut heap = MinBinaryHeap::with_store(v);
+        heap.heapify();
+        heap
+    }
+}
+
+impl<T> ::std::iter::FromIterator<T> for MinBinaryHeap<T, Vec<T>>
+    where T: Ord
+{
+    /// Collects an iterator into a heap in O(n), via `from_vec`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use min_binary_heap::MinBinaryHeap;
+    /// let queue: MinBinaryHeap<u8> = vec![10, 1, 5].into_iter().collect();
+    ///
+    /// assert_eq!(queue.size(), 3);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> MinBinaryHeap<T, Vec<T>> {
+        MinBinaryHeap::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<T> Extend<T> for MinBinaryHeap<T, Vec<T>>
+    where T: Ord
+{
+    /// Extends the heap with the contents of an iterator.
+    ///
+    /// If the heap is currently empty this heapifies once in O(n), same as `from_vec`; otherwise
+    /// it falls back to inserting each element in turn so any already-tracked `positions` stay
+    /// valid.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        if self.tree.is_empty() {
+            self.tree = iter.into_iter().collect();
+            self.heapify();
+            self.retrack_all();
+        } else {
+            for item in iter {
+                self.insert(item);
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use MinBinaryHeap;
+    use {Indexing, MinBinaryHeap};
 
     #[test]
     fn parent_index() {
@@ -281,6 +885,215 @@ mod tests {
         assert_eq!(queue.child_index_min(0), Some(1));
     }
 
+    #[derive(Debug, Eq, PartialEq)]
+    struct Entry {
+        id: usize,
+        priority: u8,
+    }
+
+    impl Indexing for Entry {
+        fn as_index(&self) -> usize {
+            self.id
+        }
+    }
+
+    impl Ord for Entry {
+        fn cmp(&self, other: &Entry) -> ::std::cmp::Ordering {
+            self.priority.cmp(&other.priority)
+        }
+    }
+
+    impl PartialOrd for Entry {
+        fn partial_cmp(&self, other: &Entry) -> Option<::std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    #[test]
+    fn decrease_key_bubbles_up_and_tracks_position() {
+        let mut queue: MinBinaryHeap<Entry> = MinBinaryHeap::with_max_index(3);
+        queue.insert(Entry { id: 0, priority: 16 });
+        queue.insert(Entry { id: 1, priority: 14 });
+        queue.insert(Entry { id: 2, priority: 10 });
+
+        assert!(queue.contains(0));
+        assert!(!queue.contains(3));
+
+        queue.decrease_key(Entry { id: 0, priority: 1 });
+
+        assert_eq!(queue.extract_min(), Some(Entry { id: 0, priority: 1 }));
+        assert!(!queue.contains(0));
+    }
+
+    #[test]
+    fn increase_key_trickles_down() {
+        let mut queue: MinBinaryHeap<Entry> = MinBinaryHeap::with_max_index(3);
+        queue.insert(Entry { id: 0, priority: 1 });
+        queue.insert(Entry { id: 1, priority: 14 });
+        queue.insert(Entry { id: 2, priority: 10 });
+
+        queue.increase_key(Entry { id: 0, priority: 16 });
+
+        assert_eq!(queue.extract_min(), Some(Entry { id: 2, priority: 10 }));
+        assert_eq!(queue.extract_min(), Some(Entry { id: 1, priority: 14 }));
+        assert_eq!(queue.extract_min(), Some(Entry { id: 0, priority: 16 }));
+    }
+
+    #[test]
+    fn with_arity_child_index_min() {
+        let mut queue: MinBinaryHeap<u8> = MinBinaryHeap::with_arity(4);
+        queue.insert(16);
+        queue.insert(14);
+        queue.insert(10);
+        queue.insert(8);
+        queue.insert(7);
+
+        // Node 0 now has up to 4 children: indexes 1, 2, 3 and 4.
+        assert_eq!(queue.child_index_min(0), Some(4));
+        assert_eq!(queue.parent_index(4), 0);
+    }
+
+    #[test]
+    fn with_arity_preserves_heap_property() {
+        let mut queue: MinBinaryHeap<u8> = MinBinaryHeap::with_arity(4);
+        queue.insert(16);
+        queue.insert(14);
+        queue.insert(10);
+        queue.insert(8);
+        queue.insert(7);
+        queue.insert(9);
+        queue.insert(3);
+
+        assert_eq!(queue.extract_min(), Some(3));
+        assert_eq!(queue.extract_min(), Some(7));
+        assert_eq!(queue.extract_min(), Some(8));
+        assert_eq!(queue.extract_min(), Some(9));
+        assert_eq!(queue.extract_min(), Some(10));
+        assert_eq!(queue.extract_min(), Some(14));
+        assert_eq!(queue.extract_min(), Some(16));
+        assert_eq!(queue.extract_min(), None);
+    }
+
+    #[test]
+    fn with_arity_and_max_index_combines_both() {
+        let mut queue: MinBinaryHeap<Entry> = MinBinaryHeap::with_arity_and_max_index(4, 3);
+        queue.insert(Entry { id: 0, priority: 16 });
+        queue.insert(Entry { id: 1, priority: 14 });
+        queue.insert(Entry { id: 2, priority: 10 });
+
+        assert_eq!(queue.arity, 4);
+        assert!(queue.contains(1));
+
+        queue.decrease_key(Entry { id: 0, priority: 1 });
+
+        assert_eq!(queue.extract_min(), Some(Entry { id: 0, priority: 1 }));
+    }
+
+    #[test]
+    fn from_vec_heapifies() {
+        let mut queue = MinBinaryHeap::from_vec(vec![16u8, 14, 10, 8, 7, 9, 3, 2, 4, 1]);
+
+        assert_eq!(queue.size(), 10);
+        assert_eq!(queue.extract_min(), Some(1));
+        assert_eq!(queue.extract_min(), Some(2));
+        assert_eq!(queue.extract_min(), Some(3));
+    }
+
+    #[test]
+    fn from_iterator_collects_into_heap() {
+        let mut queue: MinBinaryHeap<u8> = vec![16, 14, 10, 8, 7].into_iter().collect();
+
+        assert_eq!(queue.size(), 5);
+        assert_eq!(queue.extract_min(), Some(7));
+    }
+
+    #[test]
+    fn extend_on_empty_heap_heapifies() {
+        let mut queue: MinBinaryHeap<u8> = MinBinaryHeap::new();
+        queue.extend(vec![16, 14, 10]);
+
+        assert_eq!(queue.extract_min(), Some(10));
+    }
+
+    #[test]
+    fn extend_on_empty_heap_tracks_every_position() {
+        let mut queue: MinBinaryHeap<Entry> = MinBinaryHeap::with_max_index(5);
+        queue.extend(vec![
+            Entry { id: 0, priority: 16 },
+            Entry { id: 1, priority: 14 },
+            Entry { id: 2, priority: 10 },
+            Entry { id: 3, priority: 8 },
+            Entry { id: 4, priority: 7 },
+        ]);
+
+        assert!(queue.contains(2));
+        queue.decrease_key(Entry { id: 2, priority: 1 });
+
+        assert_eq!(queue.extract_min(), Some(Entry { id: 2, priority: 1 }));
+    }
+
+    #[test]
+    fn extend_on_nonempty_heap_inserts() {
+        let mut queue: MinBinaryHeap<u8> = MinBinaryHeap::new();
+        queue.insert(5);
+        queue.extend(vec![16, 1]);
+
+        assert_eq!(queue.extract_min(), Some(1));
+        assert_eq!(queue.extract_min(), Some(5));
+        assert_eq!(queue.extract_min(), Some(16));
+    }
+
+    #[test]
+    fn peek_does_not_remove() {
+        let mut queue: MinBinaryHeap<u8> = MinBinaryHeap::new();
+        assert_eq!(queue.peek(), None);
+
+        queue.insert(10);
+        queue.insert(1);
+
+        assert_eq!(queue.peek(), Some(&1));
+        assert_eq!(queue.size(), 2);
+    }
+
+    #[test]
+    fn peek_mut_re_sifts_on_drop() {
+        let mut queue: MinBinaryHeap<u8> = MinBinaryHeap::new();
+        queue.insert(10);
+        queue.insert(1);
+        queue.insert(5);
+
+        {
+            let mut smallest = queue.peek_mut().unwrap();
+            *smallest = 20;
+        }
+
+        assert_eq!(queue.extract_min(), Some(5));
+        assert_eq!(queue.extract_min(), Some(10));
+        assert_eq!(queue.extract_min(), Some(20));
+    }
+
+    #[test]
+    fn into_sorted_vec_is_ascending() {
+        let queue = MinBinaryHeap::from_vec(vec![16u8, 14, 10, 8, 7, 9, 3, 2, 4, 1]);
+
+        assert_eq!(queue.into_sorted_vec(), vec![1, 2, 3, 4, 7, 8, 9, 10, 14, 16]);
+    }
+
+    #[test]
+    fn with_store_runs_over_a_persistent_store() {
+        use store::PersistentStore;
+
+        let mut queue: MinBinaryHeap<u64, PersistentStore<u64>> =
+            MinBinaryHeap::with_store(PersistentStore::new());
+        queue.insert(10);
+        queue.insert(1);
+        queue.insert(5);
+
+        assert_eq!(queue.extract_min(), Some(1));
+        assert_eq!(queue.extract_min(), Some(5));
+        assert_eq!(queue.extract_min(), Some(10));
+    }
+
     fn setup() -> MinBinaryHeap<u8> {
         let mut queue = MinBinaryHeap::new();
         queue.insert(16);
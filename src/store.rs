@@ -0,0 +1,307 @@
+//! Pluggable backing storage for `MinBinaryHeap`, including a byte-serializable store that can be
+//! reopened from a persisted buffer.
+
+use std::error::Error;
+use std::fmt;
+
+/// Abstracts over the array `MinBinaryHeap` keeps its elements in, so the heap's sift logic can
+/// run unchanged over storage other than plain `Vec<T>`.
+///
+/// Indices passed to `get`/`get_mut`/`set`/`swap` are always within `0..self.len()`.
+pub trait HeapStore<T> {
+    /// Returns the number of elements currently stored.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if no elements are stored.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the first element, or `None` if empty.
+    fn first(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.get(0))
+        }
+    }
+
+    /// Returns a reference to the element at `index`.
+    fn get(&self, index: usize) -> &T;
+
+    /// Returns a mutable reference to the element at `index`.
+    fn get_mut(&mut self, index: usize) -> &mut T;
+
+    /// Overwrites the element at `index`.
+    fn set(&mut self, index: usize, value: T);
+
+    /// Appends an element to the end of the store.
+    fn push(&mut self, value: T);
+
+    /// Removes and returns the last element, or `None` if empty.
+    fn pop(&mut self) -> Option<T>;
+
+    /// Swaps the elements at `i` and `j`.
+    fn swap(&mut self, i: usize, j: usize);
+
+    /// Reverses the order of the stored elements.
+    fn reverse(&mut self);
+}
+
+impl<T> HeapStore<T> for Vec<T> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn get(&self, index: usize) -> &T {
+        &self[index]
+    }
+
+    fn get_mut(&mut self, index: usize) -> &mut T {
+        &mut self[index]
+    }
+
+    fn set(&mut self, index: usize, value: T) {
+        self[index] = value;
+    }
+
+    fn push(&mut self, value: T) {
+        Vec::push(self, value);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        Vec::pop(self)
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        <[T]>::swap(self, i, j);
+    }
+
+    fn reverse(&mut self) {
+        <[T]>::reverse(self);
+    }
+}
+
+/// Lets a type be written into and read back out of a `PersistentStore`'s fixed-size element
+/// slots.
+pub trait Persist: Sized {
+    /// The number of bytes each encoded element occupies.
+    const SLOT_SIZE: usize;
+
+    /// Encodes `self` into `out`, which is exactly `SLOT_SIZE` bytes long.
+    fn encode(&self, out: &mut [u8]);
+
+    /// Decodes an element from `bytes`, which is exactly `SLOT_SIZE` bytes long.
+    fn decode(bytes: &[u8]) -> Self;
+}
+
+impl Persist for u64 {
+    const SLOT_SIZE: usize = 8;
+
+    fn encode(&self, out: &mut [u8]) {
+        out.copy_from_slice(&self.to_le_bytes());
+    }
+
+    fn decode(bytes: &[u8]) -> u64 {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(bytes);
+        u64::from_le_bytes(buf)
+    }
+}
+
+/// Three-byte tag written at the start of every buffer produced by `PersistentStore::to_bytes`.
+const MAGIC: [u8; 3] = *b"MBH";
+
+/// Error returned by `PersistentStore::open` when a buffer cannot be reopened.
+#[derive(Debug, Eq, PartialEq)]
+pub enum OpenError {
+    /// The buffer's header does not start with the expected magic tag.
+    BadMagic,
+    /// The header is present and its magic tag matches, but the buffer is too short to hold the
+    /// element count the header claims.
+    Truncated,
+}
+
+impl fmt::Display for OpenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            OpenError::BadMagic => write!(f, "buffer is missing the PersistentStore magic tag"),
+            OpenError::Truncated => {
+                write!(f, "buffer is too short to hold the element count its header claims")
+            }
+        }
+    }
+}
+
+impl Error for OpenError {}
+
+/// A `HeapStore` that mirrors its elements in memory but can be serialized to, and reopened from,
+/// a byte buffer with a small fixed header: a 3-byte magic tag, a little-endian `u32` element
+/// count, then one fixed-size slot per element.
+///
+/// This lets `MinBinaryHeap<T, PersistentStore<T>>` be written to (and read back from) a
+/// memory-mapped or otherwise persisted region without duplicating any of the heap's sift code.
+pub struct PersistentStore<T: Persist> {
+    mirror: Vec<T>,
+}
+
+impl<T: Persist> PersistentStore<T> {
+    /// Creates an empty persistent store.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use min_binary_heap::PersistentStore;
+    /// let store: PersistentStore<u64> = PersistentStore::new();
+    ///
+    /// assert_eq!(store.to_bytes().len(), 3 + 4);
+    /// ```
+    pub fn new() -> PersistentStore<T> {
+        PersistentStore { mirror: Vec::new() }
+    }
+
+    /// Serializes the store into a header (magic tag, element count) followed by one
+    /// fixed-size slot per element.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use min_binary_heap::{HeapStore, PersistentStore};
+    /// let mut store: PersistentStore<u64> = PersistentStore::new();
+    /// store.push(10);
+    ///
+    /// let bytes = store.to_bytes();
+    /// let reopened: PersistentStore<u64> = PersistentStore::open(&bytes).unwrap();
+    ///
+    /// assert_eq!(reopened.len(), 1);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(3 + 4 + self.mirror.len() * T::SLOT_SIZE);
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&(self.mirror.len() as u32).to_le_bytes());
+
+        let mut slot = vec![0u8; T::SLOT_SIZE];
+        for element in &self.mirror {
+            element.encode(&mut slot);
+            bytes.extend_from_slice(&slot);
+        }
+        bytes
+    }
+
+    /// Reopens a store previously written by `to_bytes`, validating the magic tag and that
+    /// `bytes` is long enough to hold the element count it claims to.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenError::BadMagic` if `bytes` does not start with the expected magic tag, or
+    /// `OpenError::Truncated` if it does but is too short to hold the element count the header
+    /// claims.
+    pub fn open(bytes: &[u8]) -> Result<PersistentStore<T>, OpenError> {
+        if bytes.len() < 7 || bytes[0..3] != MAGIC {
+            return Err(OpenError::BadMagic);
+        }
+
+        let mut count_bytes = [0u8; 4];
+        count_bytes.copy_from_slice(&bytes[3..7]);
+        let count = u32::from_le_bytes(count_bytes) as usize;
+
+        if bytes.len() < 7 + count * T::SLOT_SIZE {
+            return Err(OpenError::Truncated);
+        }
+
+        let mut mirror = Vec::with_capacity(count);
+        let mut offset = 7;
+        for _ in 0..count {
+            mirror.push(T::decode(&bytes[offset..offset + T::SLOT_SIZE]));
+            offset += T::SLOT_SIZE;
+        }
+        Ok(PersistentStore { mirror })
+    }
+}
+
+impl<T: Persist> Default for PersistentStore<T> {
+    fn default() -> PersistentStore<T> {
+        PersistentStore::new()
+    }
+}
+
+impl<T: Persist> HeapStore<T> for PersistentStore<T> {
+    fn len(&self) -> usize {
+        self.mirror.len()
+    }
+
+    fn get(&self, index: usize) -> &T {
+        &self.mirror[index]
+    }
+
+    fn get_mut(&mut self, index: usize) -> &mut T {
+        &mut self.mirror[index]
+    }
+
+    fn set(&mut self, index: usize, value: T) {
+        self.mirror[index] = value;
+    }
+
+    fn push(&mut self, value: T) {
+        self.mirror.push(value);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.mirror.pop()
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.mirror.swap(i, j);
+    }
+
+    fn reverse(&mut self) {
+        self.mirror.reverse();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {HeapStore, OpenError, PersistentStore};
+
+    #[test]
+    fn round_trips_through_bytes() {
+        let mut store: PersistentStore<u64> = PersistentStore::new();
+        store.push(10);
+        store.push(1);
+        store.push(5);
+
+        let bytes = store.to_bytes();
+        let reopened: PersistentStore<u64> = PersistentStore::open(&bytes).unwrap();
+
+        assert_eq!(reopened.len(), 3);
+        assert_eq!(*reopened.get(0), 10);
+        assert_eq!(*reopened.get(1), 1);
+        assert_eq!(*reopened.get(2), 5);
+    }
+
+    #[test]
+    fn open_rejects_bad_magic() {
+        let bytes = vec![0u8, 1, 2, 3, 4, 5, 6];
+
+        match PersistentStore::<u64>::open(&bytes) {
+            Err(OpenError::BadMagic) => {}
+            other => panic!("expected OpenError::BadMagic, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn open_rejects_truncated_element_data() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"MBH");
+        bytes.extend_from_slice(&100u32.to_le_bytes());
+
+        match PersistentStore::<u64>::open(&bytes) {
+            Err(OpenError::Truncated) => {}
+            other => panic!("expected OpenError::Truncated, got {:?}", other.map(|_| ())),
+        }
+    }
+}